@@ -32,18 +32,77 @@
 //! assert_eq!("string".try_slice(4..2), None);
 //! ```
 //!
+//! The [`StringSliceMut`] trait provides the same slicing, but returns a `&mut str`
+//! so the result can be mutated in place.
+//!
+//! ```
+//! use stringslice::StringSliceMut;
+//!
+//! let mut string = String::from("hello world");
+//! string.slice_mut(..5).make_ascii_uppercase();
+//! assert_eq!(string, "HELLO world");
+//! ```
+//!
+//! Negative, end-relative character indices are supported via [`slice_signed`] and
+//! [`substring_signed`], so the last `N` characters can be taken without counting the
+//! string first.
+//!
+//! ```
+//! use stringslice::StringSlice;
+//!
+//! assert_eq!("Ùníc😎de".slice_signed(-3..), "😎de");
+//! ```
+//!
+//! [`char_find`], [`char_rfind`], and [`slice_between`] locate a pattern and report its
+//! position in characters rather than bytes.
+//!
+//! ```
+//! use stringslice::StringSlice;
+//!
+//! assert_eq!("<tag>content</tag>".slice_between("<tag>", "</tag>"), Some("content"));
+//! ```
+//!
+//! [`char_chunks`] iterates over the string in fixed-size chunks of characters, useful for
+//! wrapping or paginating text.
+//!
+//! ```
+//! use stringslice::StringSlice;
+//!
+//! let mut chunks = "abcdef".char_chunks(2);
+//! assert_eq!(chunks.next(), Some("ab"));
+//! assert_eq!(chunks.next(), Some("cd"));
+//! assert_eq!(chunks.next(), Some("ef"));
+//! ```
+//!
+//! The [`CharsRange`] wrapper type gives the same character-index semantics through the
+//! indexing operator.
+//!
+//! ```
+//! use stringslice::CharsRange;
+//!
+//! assert_eq!(&"Ùníc😎de"[CharsRange(4..5)], "😎");
+//! ```
+//!
 //! [`StringSlice`]: trait.StringSlice.html
+//! [`StringSliceMut`]: trait.StringSliceMut.html
+//! [`CharsRange`]: struct.CharsRange.html
 //! [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
 //! [`slice`]: trait.StringSlice.html#method.slice
 //! [`substring`]: trait.StringSlice.html#method.substring
 //! [`try_slice`]: trait.StringSlice.html#method.try_slice
 //! [`try_substring`]: trait.StringSlice.html#method.try_substring
+//! [`slice_signed`]: trait.StringSlice.html#method.slice_signed
+//! [`substring_signed`]: trait.StringSlice.html#method.substring_signed
+//! [`char_find`]: trait.StringSlice.html#method.char_find
+//! [`char_rfind`]: trait.StringSlice.html#method.char_rfind
+//! [`slice_between`]: trait.StringSlice.html#method.slice_between
+//! [`char_chunks`]: trait.StringSlice.html#method.char_chunks
 //! [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
 //!
 
 #![no_std]
 
-use core::ops::{Bound, RangeBounds};
+use core::ops::{self, Bound, RangeBounds};
 
 fn range_to_begin_end(range: impl RangeBounds<usize>) -> (usize, usize) {
     let begin = match range.start_bound() {
@@ -61,12 +120,171 @@ fn range_to_begin_end(range: impl RangeBounds<usize>) -> (usize, usize) {
     (begin, end)
 }
 
-/// Provides the [`slice`], [`try_slice`], [`substring`], and [`try_substring`] methods.
+/// Converts a `[begin, end)` character range into the equivalent `[begin, end)` byte range,
+/// or [`None`] if `begin` is greater than `end`.
+///
+/// Shared by [`StringSlice::try_substring`] and [`StringSliceMut::try_substring_mut`], which
+/// only differ in whether they hand back a `&str` or a `&mut str` for the resulting byte
+/// range.
+fn char_range_to_byte_range(s: &str, begin: usize, end: usize) -> Option<(usize, usize)> {
+    if begin > end {
+        None
+    } else {
+        let mut ch_idx = s.char_indices().map(|(i, _c)| i);
+
+        let len = s.len();
+        let begin_byte = ch_idx.nth(begin).unwrap_or(len);
+        let end_byte = ch_idx.nth(end - begin - 1).unwrap_or(len);
+
+        Some((begin_byte, end_byte))
+    }
+}
+
+fn range_to_begin_end_signed(range: impl RangeBounds<isize>) -> (isize, isize) {
+    // One past character `-1` is the end of the string, not character `0`.
+    let begin = match range.start_bound() {
+        Bound::Included(&b) => b,
+        Bound::Excluded(&-1) => isize::MAX,
+        Bound::Excluded(&b) => b + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&-1) => isize::MAX,
+        Bound::Included(&b) => b + 1,
+        Bound::Excluded(&b) => b,
+        Bound::Unbounded => isize::MAX,
+    };
+
+    (begin, end)
+}
+
+/// The reason a signed `[begin, end)` character range failed to resolve to a valid byte
+/// range, as reported by [`try_resolve_signed_range`].
+enum SignedRangeError {
+    /// `end` is a negative index further from the end of the string than it has characters.
+    EndOutOfRange,
+    /// `begin` resolves to a later character than `end`.
+    BeginAfterEnd,
+}
+
+/// Resolves a signed `[begin, end)` character range into the equivalent `[begin, end)` byte
+/// range, shared by [`StringSlice::substring_signed`] and [`StringSlice::try_substring_signed`].
+///
+/// An out-of-range negative `begin` clamps to the start of the string rather than failing, but
+/// an out-of-range `end` and a `begin` that resolves past `end` are reported as distinct
+/// [`SignedRangeError`] variants so `substring_signed` can still panic with a message specific
+/// to the failure, without re-deriving either bound itself.
+fn try_resolve_signed_range(
+    s: &str,
+    begin: isize,
+    end: isize,
+) -> Result<(usize, usize), SignedRangeError> {
+    let begin_ch = resolve_signed_bound(s, begin).unwrap_or(0);
+    let end_ch = resolve_signed_bound(s, end).ok_or(SignedRangeError::EndOutOfRange)?;
+
+    if begin_ch > end_ch {
+        Err(SignedRangeError::BeginAfterEnd)
+    } else {
+        Ok((begin_ch, end_ch))
+    }
+}
+
+/// Resolves a signed character index to the byte offset of the character at that position.
+///
+/// A non-negative `idx` is a plain character count from the start, walked with a forward
+/// `nth` (clamping to the end of the string if `idx` is past it). A negative `idx` counts
+/// back from the end of the string, walked with a single `nth` over the *reversed*
+/// `char_indices`, so it costs a single backward pass rather than a full forward count.
+/// Returns [`None`] if `idx` is negative and further from the end than the string has
+/// characters.
+fn resolve_signed_bound(s: &str, idx: isize) -> Option<usize> {
+    if idx >= 0 {
+        Some(
+            s.char_indices()
+                .map(|(i, _c)| i)
+                .nth(idx as usize)
+                .unwrap_or(s.len()),
+        )
+    } else {
+        let k = idx.unsigned_abs();
+        s.char_indices().rev().map(|(i, _c)| i).nth(k - 1)
+    }
+}
+
+/// A thing that can be searched for in a string, analogous to [`core::str`]'s unstable
+/// `Pattern` trait.
+///
+/// The real `core::str::pattern::Pattern` cannot be named in a generic bound on stable Rust
+/// (see [rust-lang/rust#27721]), so [`char_find`], [`char_rfind`], and [`slice_between`] are
+/// generic over this crate's own minimal stand-in instead. It is implemented for [`char`]
+/// and [`&str`], matching the patterns those methods need to support.
+///
+/// [rust-lang/rust#27721]: https://github.com/rust-lang/rust/issues/27721
+/// [`char_find`]: trait.StringSlice.html#method.char_find
+/// [`char_rfind`]: trait.StringSlice.html#method.char_rfind
+/// [`slice_between`]: trait.StringSlice.html#method.slice_between
+pub trait Pattern {
+    /// Returns the byte range of the first match of this pattern in `haystack`.
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the byte range of the last match of this pattern in `haystack`.
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)>;
+}
+
+impl Pattern for char {
+    #[inline]
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(*self).map(|i| (i, i + self.len_utf8()))
+    }
+
+    #[inline]
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.rfind(*self).map(|i| (i, i + self.len_utf8()))
+    }
+}
+
+impl Pattern for &str {
+    #[inline]
+    fn find_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(*self).map(|i| (i, i + self.len()))
+    }
+
+    #[inline]
+    fn rfind_in(&self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.rfind(*self).map(|i| (i, i + self.len()))
+    }
+}
+
+/// Provides the [`slice`], [`try_slice`], [`substring`], [`try_substring`], [`char_split_at`],
+/// and [`try_char_split_at`] methods.
 ///
 /// [`slice`]: trait.StringSlice.html#method.slice
 /// [`substring`]: trait.StringSlice.html#method.substring
 /// [`try_slice`]: trait.StringSlice.html#method.try_slice
 /// [`try_substring`]: trait.StringSlice.html#method.try_substring
+/// [`char_split_at`]: trait.StringSlice.html#method.char_split_at
+/// [`try_char_split_at`]: trait.StringSlice.html#method.try_char_split_at
+///
+/// It also provides the end-relative [`slice_signed`], [`try_slice_signed`],
+/// [`substring_signed`], and [`try_substring_signed`] methods.
+///
+/// [`slice_signed`]: trait.StringSlice.html#method.slice_signed
+/// [`try_slice_signed`]: trait.StringSlice.html#method.try_slice_signed
+/// [`substring_signed`]: trait.StringSlice.html#method.substring_signed
+/// [`try_substring_signed`]: trait.StringSlice.html#method.try_substring_signed
+///
+/// Finally, [`char_find`], [`char_rfind`], and [`slice_between`] mirror [`core::str`]'s
+/// pattern-based search, but report positions in characters rather than bytes.
+///
+/// [`char_find`]: trait.StringSlice.html#method.char_find
+/// [`char_rfind`]: trait.StringSlice.html#method.char_rfind
+/// [`slice_between`]: trait.StringSlice.html#method.slice_between
+///
+/// It also provides [`char_chunks`], which iterates over the string in fixed-size chunks
+/// of characters.
+///
+/// [`char_chunks`]: trait.StringSlice.html#method.char_chunks
 pub trait StringSlice {
     /// Returns a string slice for the given range of characters
     ///
@@ -123,6 +341,173 @@ pub trait StringSlice {
     /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
     fn try_substring(&self, begin: usize, end: usize) -> Option<&str>;
+
+    /// Divides a string into two at the given character index, analogous to [`str::split_at`]
+    /// but where `mid` counts characters rather than bytes.
+    ///
+    /// If `mid` is past the end of the string, the whole string is returned as the first half
+    /// and the second half is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("Ùníc😎de".char_split_at(4), ("Ùníc", "😎de"));
+    /// assert_eq!("string".char_split_at(500), ("string", ""));
+    /// ```
+    ///
+    /// [`str::split_at`]: https://doc.rust-lang.org/std/primitive.str.html#method.split_at
+    fn char_split_at(&self, mid: usize) -> (&str, &str);
+
+    /// Returns an [`Option`] containing a string divided into two at the given character index
+    ///
+    /// This is equivalent to [`char_split_at`], but wraps the result in [`Some`] for
+    /// consistency with the other `try_` methods in this trait.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("Ùníc😎de".try_char_split_at(4), Some(("Ùníc", "😎de")));
+    /// ```
+    /// [`char_split_at`]: trait.StringSlice.html#method.char_split_at
+    /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    fn try_char_split_at(&self, mid: usize) -> Option<(&str, &str)>;
+
+    /// Returns a string slice for the given range of characters, where a negative bound `k`
+    /// refers to character position `char_count + k`, i.e. counting back from the end of the
+    /// string.
+    ///
+    /// This method will panic if the range is invalid, for example if the beginning is
+    /// greater than the end, or if a negative end is further from the end than the string
+    /// has characters. An out-of-range negative beginning clamps to the start of the string
+    /// instead of panicking.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("Ùníc😎de".slice_signed(-3..), "😎de");
+    /// assert_eq!("Ùníc😎de".slice_signed(..-2), "Ùníc😎");
+    /// ```
+    fn slice_signed(&self, range: impl RangeBounds<isize>) -> &str;
+
+    /// Returns an [`Option`] containing a string slice for the given range of characters,
+    /// where a negative bound `k` refers to character position `char_count + k`
+    ///
+    /// This method will return [`None`] if the range is invalid, for example if the
+    /// beginning is greater than the end, or if a negative end is further from the end
+    /// than the string has characters. An out-of-range negative beginning clamps to the
+    /// start of the string instead of yielding [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("Ùníc😎de".try_slice_signed(-3..), Some("😎de"));
+    /// ```
+    /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    fn try_slice_signed(&self, range: impl RangeBounds<isize>) -> Option<&str>;
+
+    /// Returns a string slice between the given beginning and end characters, where a
+    /// negative `begin` or `end` refers to character position `char_count + begin` (or
+    /// `+ end`), i.e. counting back from the end of the string.
+    ///
+    /// This method will panic if `end` is a negative index further from the end of the
+    /// string than it has characters, or if `begin` resolves to a later character than
+    /// `end`. An out-of-range negative `begin` clamps to the start of the string instead
+    /// of panicking.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("Ùníc😎de".substring_signed(-3, -1), "😎d");
+    /// ```
+    fn substring_signed(&self, begin: isize, end: isize) -> &str;
+
+    /// Returns an [`Option`] containing a string slice between the given beginning and end
+    /// characters, where a negative `begin` or `end` refers to character position
+    /// `char_count + begin` (or `+ end`).
+    ///
+    /// This method will return [`None`] if the parameters are invalid, for example if the
+    /// beginning is greater than the end, or if a negative `end` is further from the end
+    /// than the string has characters. An out-of-range negative `begin` clamps to the start
+    /// of the string instead of yielding [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("Ùníc😎de".try_substring_signed(-3, -1), Some("😎d"));
+    /// ```
+    /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    fn try_substring_signed(&self, begin: isize, end: isize) -> Option<&str>;
+
+    /// Returns the character index of the first match of a [`Pattern`] in the string
+    ///
+    /// This is analogous to [`str::find`], but reports the result as a character count
+    /// rather than a byte offset.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("Ùníc😎de".char_find('😎'), Some(4));
+    /// assert_eq!("Ùníc😎de".char_find("xyz"), None);
+    /// ```
+    ///
+    /// [`str::find`]: https://doc.rust-lang.org/std/primitive.str.html#method.find
+    fn char_find<P: Pattern>(&self, pat: P) -> Option<usize>;
+
+    /// Returns the character index of the last match of a [`Pattern`] in the string
+    ///
+    /// This is analogous to [`str::rfind`], but reports the result as a character count
+    /// rather than a byte offset.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("abc😎abc".char_rfind('a'), Some(4));
+    /// ```
+    ///
+    /// [`str::rfind`]: https://doc.rust-lang.org/std/primitive.str.html#method.rfind
+    fn char_rfind<P: Pattern>(&self, pat: P) -> Option<usize>;
+
+    /// Returns the string slice between the first match of `start_pat` and the next match
+    /// of `end_pat` after it, excluding both patterns.
+    ///
+    /// Returns [`None`] if either pattern cannot be found.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// assert_eq!("<tag>content</tag>".slice_between("<tag>", "</tag>"), Some("content"));
+    /// ```
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    fn slice_between<P: Pattern, Q: Pattern>(&self, start_pat: P, end_pat: Q) -> Option<&str>;
+
+    /// Returns an iterator over `n`-character chunks of the string, with the final chunk
+    /// holding the remainder.
+    ///
+    /// This method will panic if `n` is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSlice;
+    ///
+    /// let mut chunks = "Ùníc😎de".char_chunks(3);
+    /// assert_eq!(chunks.next(), Some("Ùní"));
+    /// assert_eq!(chunks.next(), Some("c😎d"));
+    /// assert_eq!(chunks.next(), Some("e"));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    fn char_chunks(&self, n: usize) -> CharChunks<'_>;
 }
 
 impl StringSlice for str {
@@ -146,25 +531,255 @@ impl StringSlice for str {
 
     #[inline]
     fn try_substring(&self, begin: usize, end: usize) -> Option<&str> {
-        if begin > end {
-            None
-        } else {
-            let mut ch_idx = self.char_indices().map(|(i, _c)| i);
+        let (begin_byte, end_byte) = char_range_to_byte_range(self, begin, end)?;
+        Some(&self[begin_byte..end_byte])
+    }
 
-            let len = self.len();
-            let begin_ch = ch_idx.nth(begin).unwrap_or(len);
-            let end_ch = ch_idx.nth(end - begin - 1).unwrap_or(len);
+    #[inline]
+    fn char_split_at(&self, mid: usize) -> (&str, &str) {
+        let byte_mid = self
+            .char_indices()
+            .map(|(i, _c)| i)
+            .nth(mid)
+            .unwrap_or(self.len());
+
+        self.split_at(byte_mid)
+    }
+
+    #[inline]
+    fn try_char_split_at(&self, mid: usize) -> Option<(&str, &str)> {
+        Some(self.char_split_at(mid))
+    }
 
-            Some(&self[begin_ch..end_ch])
+    #[inline]
+    fn slice_signed(&self, range: impl RangeBounds<isize>) -> &str {
+        let (begin, end) = range_to_begin_end_signed(range);
+        self.substring_signed(begin, end)
+    }
+
+    #[inline]
+    fn try_slice_signed(&self, range: impl RangeBounds<isize>) -> Option<&str> {
+        let (begin, end) = range_to_begin_end_signed(range);
+        self.try_substring_signed(begin, end)
+    }
+
+    #[inline]
+    fn substring_signed(&self, begin: isize, end: isize) -> &str {
+        let (begin_ch, end_ch) = match try_resolve_signed_range(self, begin, end) {
+            Ok(range) => range,
+            Err(SignedRangeError::EndOutOfRange) => {
+                panic!("end index out of range when slicing string")
+            }
+            Err(SignedRangeError::BeginAfterEnd) => panic!("begin < end when slicing string"),
+        };
+
+        &self[begin_ch..end_ch]
+    }
+
+    #[inline]
+    fn try_substring_signed(&self, begin: isize, end: isize) -> Option<&str> {
+        let (begin_ch, end_ch) = try_resolve_signed_range(self, begin, end).ok()?;
+        Some(&self[begin_ch..end_ch])
+    }
+
+    #[inline]
+    fn char_find<P: Pattern>(&self, pat: P) -> Option<usize> {
+        let (start, _end) = pat.find_in(self)?;
+        Some(self[..start].chars().count())
+    }
+
+    #[inline]
+    fn char_rfind<P: Pattern>(&self, pat: P) -> Option<usize> {
+        let (start, _end) = pat.rfind_in(self)?;
+        Some(self[..start].chars().count())
+    }
+
+    #[inline]
+    fn slice_between<P: Pattern, Q: Pattern>(&self, start_pat: P, end_pat: Q) -> Option<&str> {
+        let (_start, after_start) = start_pat.find_in(self)?;
+        let (before_end, _end) = end_pat.find_in(&self[after_start..])?;
+
+        Some(&self[after_start..after_start + before_end])
+    }
+
+    #[inline]
+    fn char_chunks(&self, n: usize) -> CharChunks<'_> {
+        assert!(n > 0, "chunk size must be non-zero");
+        CharChunks { remainder: self, n }
+    }
+}
+
+/// An iterator over `n`-character chunks of a string.
+///
+/// This struct is created by the [`char_chunks`] method. See its documentation for more.
+///
+/// [`char_chunks`]: trait.StringSlice.html#method.char_chunks
+#[derive(Debug, Clone)]
+pub struct CharChunks<'a> {
+    remainder: &'a str,
+    n: usize,
+}
+
+impl<'a> Iterator for CharChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.remainder.is_empty() {
+            return None;
         }
+
+        let (chunk, rest) = self.remainder.char_split_at(self.n);
+        self.remainder = rest;
+        Some(chunk)
+    }
+}
+
+/// Provides the [`slice_mut`], [`try_slice_mut`], [`substring_mut`], and [`try_substring_mut`]
+/// methods.
+///
+/// These mirror the [`StringSlice`] methods, but hand back a `&mut str` for the given
+/// character range so the caller can mutate the substring in place (e.g. via
+/// [`make_ascii_uppercase`]) without reslicing by hand.
+///
+/// [`slice_mut`]: trait.StringSliceMut.html#method.slice_mut
+/// [`substring_mut`]: trait.StringSliceMut.html#method.substring_mut
+/// [`try_slice_mut`]: trait.StringSliceMut.html#method.try_slice_mut
+/// [`try_substring_mut`]: trait.StringSliceMut.html#method.try_substring_mut
+/// [`make_ascii_uppercase`]: https://doc.rust-lang.org/std/primitive.str.html#method.make_ascii_uppercase
+pub trait StringSliceMut {
+    /// Returns a mutable string slice for the given range of characters
+    ///
+    /// This method will panic if the range is invalid,
+    /// for example if the beginning is greater than the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSliceMut;
+    ///
+    /// let mut string = String::from("hello world");
+    /// string.slice_mut(..5).make_ascii_uppercase();
+    /// assert_eq!(string, "HELLO world");
+    /// ```
+    fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> &mut str;
+
+    /// Returns an [`Option`] containing a mutable string slice for the given range of characters
+    ///
+    /// This method will return [`None`] if the range is invalid,
+    /// for example if the beginning is greater than the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSliceMut;
+    ///
+    /// let mut string = String::from("hello world");
+    /// assert!(string.try_slice_mut(..5).is_some());
+    /// ```
+    /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    fn try_slice_mut(&mut self, range: impl RangeBounds<usize>) -> Option<&mut str>;
+
+    /// Returns a mutable string slice between the given beginning and end characters
+    ///
+    /// This method will panic if the parameters are invalid,
+    /// for example if the beginning is greater than the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSliceMut;
+    ///
+    /// let mut string = String::from("hello world");
+    /// string.substring_mut(0, 5).make_ascii_uppercase();
+    /// assert_eq!(string, "HELLO world");
+    /// ```
+    fn substring_mut(&mut self, begin: usize, end: usize) -> &mut str;
+
+    /// Returns an [`Option`] containing a mutable string slice between the given beginning and
+    /// end characters
+    ///
+    /// This method will return [`None`] if the parameters are invalid,
+    /// for example if the beginning is greater than the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use stringslice::StringSliceMut;
+    ///
+    /// let mut string = String::from("Ùníc😎de");
+    /// assert!(string.try_substring_mut(4, 5).is_some());
+    /// ```
+    /// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    fn try_substring_mut(&mut self, begin: usize, end: usize) -> Option<&mut str>;
+}
+
+impl StringSliceMut for str {
+    #[inline]
+    fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> &mut str {
+        let (begin, end) = range_to_begin_end(range);
+        self.substring_mut(begin, end)
+    }
+
+    #[inline]
+    fn try_slice_mut(&mut self, range: impl RangeBounds<usize>) -> Option<&mut str> {
+        let (begin, end) = range_to_begin_end(range);
+        self.try_substring_mut(begin, end)
+    }
+
+    #[inline]
+    fn substring_mut(&mut self, begin: usize, end: usize) -> &mut str {
+        self.try_substring_mut(begin, end)
+            .expect("begin < end when slicing string")
+    }
+
+    #[inline]
+    fn try_substring_mut(&mut self, begin: usize, end: usize) -> Option<&mut str> {
+        let (begin_byte, end_byte) = char_range_to_byte_range(self, begin, end)?;
+        Some(&mut self[begin_byte..end_byte])
+    }
+}
+
+/// A wrapper around a [`RangeBounds<usize>`] that indexes a string by character range
+/// rather than by byte range, for use with the indexing operator.
+///
+/// This mirrors how [`core::str`] uses `SliceIndex` to back its own `[]` indexing, giving
+/// [`slice`]'s character semantics through `s[CharsRange(range)]` instead of a method call.
+///
+/// # Examples
+/// ```
+/// use stringslice::CharsRange;
+///
+/// assert_eq!(&"Ùníc😎de"[CharsRange(4..5)], "😎");
+/// ```
+///
+/// [`RangeBounds<usize>`]: https://doc.rust-lang.org/core/ops/trait.RangeBounds.html
+/// [`slice`]: trait.StringSlice.html#method.slice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharsRange<R: RangeBounds<usize>>(pub R);
+
+impl<R: RangeBounds<usize>> ops::Index<CharsRange<R>> for str {
+    type Output = str;
+
+    #[inline]
+    fn index(&self, index: CharsRange<R>) -> &str {
+        self.slice(index.0)
+    }
+}
+
+impl<R: RangeBounds<usize>> ops::IndexMut<CharsRange<R>> for str {
+    #[inline]
+    fn index_mut(&mut self, index: CharsRange<R>) -> &mut str {
+        self.slice_mut(index.0)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
     use core::ops::Bound;
+    use std::string::String;
+    use std::vec::Vec;
 
-    use super::StringSlice;
+    use super::{CharsRange, StringSlice, StringSliceMut};
 
     #[test]
     #[should_panic]
@@ -194,4 +809,144 @@ mod tests {
             "str"
         );
     }
+
+    #[test]
+    #[should_panic]
+    fn test_mut_bad_range() {
+        let mut string = String::from("string");
+        string.slice_mut(4..1);
+    }
+
+    #[test]
+    fn test_mut_try_bad_range() {
+        let mut string = String::from("string");
+        assert_eq!(string.try_slice_mut(4..1), None);
+    }
+
+    #[test]
+    fn test_slice_mut() {
+        let mut string = String::from("Ùníc😎de");
+        string.slice_mut(5..7).make_ascii_uppercase();
+        assert_eq!(string, "Ùníc😎DE");
+    }
+
+    #[test]
+    fn test_substring_mut() {
+        let mut string = String::from("hello world");
+        string.substring_mut(0, 5).make_ascii_uppercase();
+        assert_eq!(string, "HELLO world");
+    }
+
+    #[test]
+    fn test_char_split_at() {
+        assert_eq!("Ùníc😎de".char_split_at(4), ("Ùníc", "😎de"));
+        assert_eq!("test_string".char_split_at(0), ("", "test_string"));
+    }
+
+    #[test]
+    fn test_char_split_at_past_end() {
+        assert_eq!("string".char_split_at(500), ("string", ""));
+    }
+
+    #[test]
+    fn test_try_char_split_at() {
+        assert_eq!("Ùníc😎de".try_char_split_at(4), Some(("Ùníc", "😎de")));
+    }
+
+    #[test]
+    fn test_slice_signed() {
+        assert_eq!("Ùníc😎de".slice_signed(-3..), "😎de");
+        assert_eq!("Ùníc😎de".slice_signed(..-2), "Ùníc😎");
+        assert_eq!("Ùníc😎de".slice_signed(-3..-1), "😎d");
+        assert_eq!("Ùníc😎de".slice_signed(1..-1), "níc😎d");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_signed_bad_range() {
+        "string".slice_signed(-1..-3);
+    }
+
+    #[test]
+    fn test_try_slice_signed_out_of_range() {
+        assert_eq!("string".try_slice_signed(-100..), Some("string"));
+        assert_eq!("string".try_slice_signed(..-100), None);
+    }
+
+    #[test]
+    fn test_substring_signed() {
+        assert_eq!("Ùníc😎de".substring_signed(-3, -1), "😎d");
+    }
+
+    #[test]
+    #[should_panic(expected = "begin < end when slicing string")]
+    fn test_substring_signed_bad_range() {
+        "string".substring_signed(3, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "end index out of range when slicing string")]
+    fn test_substring_signed_end_out_of_range() {
+        "string".substring_signed(0, -100);
+    }
+
+    #[test]
+    fn test_try_substring_signed() {
+        assert_eq!("Ùníc😎de".try_substring_signed(-3, -1), Some("😎d"));
+        assert_eq!("string".try_substring_signed(-100, 3), Some("str"));
+        assert_eq!("string".try_substring_signed(0, -100), None);
+    }
+
+    #[test]
+    fn test_char_find() {
+        assert_eq!("Ùníc😎de".char_find('😎'), Some(4));
+        assert_eq!("Ùníc😎de".char_find("😎de"), Some(4));
+        assert_eq!("Ùníc😎de".char_find("xyz"), None);
+    }
+
+    #[test]
+    fn test_char_rfind() {
+        assert_eq!("abc😎abc".char_rfind('a'), Some(4));
+        assert_eq!("abc😎abc".char_rfind("xyz"), None);
+    }
+
+    #[test]
+    fn test_slice_between() {
+        assert_eq!(
+            "<tag>content</tag>".slice_between("<tag>", "</tag>"),
+            Some("content")
+        );
+        assert_eq!("no delimiters here".slice_between("<tag>", "</tag>"), None);
+    }
+
+    #[test]
+    fn test_char_chunks() {
+        let chunks: Vec<_> = "Ùníc😎de".char_chunks(3).collect();
+        assert_eq!(chunks, ["Ùní", "c😎d", "e"]);
+    }
+
+    #[test]
+    fn test_char_chunks_exact_multiple() {
+        let chunks: Vec<_> = "abcdef".char_chunks(2).collect();
+        assert_eq!(chunks, ["ab", "cd", "ef"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_char_chunks_zero() {
+        "string".char_chunks(0);
+    }
+
+    #[test]
+    fn test_chars_range_index() {
+        assert_eq!(&"Ùníc😎de"[CharsRange(4..5)], "😎");
+        assert_eq!(&"test_string"[CharsRange(5..)], "string");
+    }
+
+    #[test]
+    fn test_chars_range_index_mut() {
+        let mut string = String::from("hello world");
+        string.as_mut_str()[CharsRange(..5)].make_ascii_uppercase();
+        assert_eq!(string, "HELLO world");
+    }
 }